@@ -4,8 +4,11 @@
 use std::cell::RefCell;
 use std::{collections::HashMap};
 use std::pin::Pin;
-use windows::{core::*, s, Win32::{Foundation::*, Graphics::Gdi::*, System::LibraryLoader::*, UI::WindowsAndMessaging::*, }, };
+use windows::{core::*, s, Win32::{Foundation::*, Graphics::Gdi::*, System::LibraryLoader::*, UI::{HiDpi::*, Input::*, Input::KeyboardAndMouse::*, Shell::*, WindowsAndMessaging::*}, }, };
 use common::geo::{Vector2, Rect2};
+use std::ffi::OsString;
+use std::os::windows::ffi::OsStringExt;
+use std::path::PathBuf;
 
 use std::sync::mpsc::{
     channel,
@@ -15,89 +18,96 @@ use std::sync::mpsc::{
 
 const CLASS_NAME: PCSTR = s!("RGUIWC");
 
+/// Identifies a window by its `HWND`, so events pumped by [`Platform::run`]
+/// can be attributed to the window they came from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WindowId(pub isize);
+
 #[derive(Debug)]
-pub struct Window<'p> {
-    platform       : &'p Platform,
-    event_receiver : Receiver<WindowEvent>,
-    /// ONLY to be utilized by the window procedure
-    event_sender   : Pin<Box<Sender<WindowEvent>>>,
+pub struct Window {
+    id             : WindowId,
     handle         : HWND,
     device_context : HDC,
-    pub minimized  : bool,
-    pub mouse      : Mouse,
-    pub size       : Rect2,
+    cursor_visible : bool,
+    /// Physical pixels per logical pixel, i.e. the monitor DPI over 96, as
+    /// sampled when the window was created. [`Platform::run`] owns dispatch and
+    /// hands out the [`Window`] as a separate value, so this field is *not*
+    /// refreshed when the window moves to another monitor; callers must track
+    /// the scale from [`WindowEvent::ScaleFactorChanged`] to size framebuffers.
+    pub scale_factor : f64,
+    pub size         : Rect2,
 }
 
-impl<'p> Window<'p> {
-    pub fn new(platform: &'p Platform) -> Self {
-        let window_name = s!("Rust GUI");
-        let (tx, rx)    = channel::<WindowEvent>();
-        let pinboxed_sender = Box::pin(tx);
-        let handle  = unsafe {
-            CreateWindowExA(
-                WINDOW_EX_STYLE(0),
-                CLASS_NAME,
-                window_name,
-                WS_OVERLAPPEDWINDOW | WS_VISIBLE,
-                CW_USEDEFAULT,
-                CW_USEDEFAULT,
-                900_i32,
-                600_i32,
-                HWND(0),
-                HMENU(0),
-                platform.process_handle,
-                Some((pinboxed_sender.as_ref().get_ref() as *const Sender<WindowEvent>).cast()),
-            )
+impl Window {
+    /// The id this window is tagged with in [`Platform::run`]'s dispatch.
+    pub fn id(&self) -> WindowId {
+        self.id
+    }
+
+    /// Opt in to (or out of) raw mouse input for sub-pixel relative motion.
+    ///
+    /// Raw input bypasses pointer ballistics and the cursor clamp, so it keeps
+    /// delivering deltas even when the cursor is pinned at a screen edge. The
+    /// absolute [`WindowEvent::MouseMoved`] path is left untouched for UI work.
+    pub fn set_raw_mouse(&self, enable: bool) {
+        let device = RAWINPUTDEVICE {
+            usUsagePage : 0x01,
+            usUsage     : 0x02,
+            dwFlags     : if enable { RIDEV_INPUTSINK } else { RIDEV_REMOVE },
+            hwndTarget  : self.handle,
         };
-        
-        Self {
-            handle,
-            platform,
-            event_receiver : rx,
-            event_sender   : pinboxed_sender,
-            minimized      : false, 
-            mouse          : Mouse::default(), 
-            size           : Rect2::new(900, 600), 
-            device_context : unsafe { GetDC(handle) }
+        unsafe {
+            RegisterRawInputDevices(&[device], std::mem::size_of::<RAWINPUTDEVICE>() as u32);
         }
     }
 
-    pub fn process_messages(&mut self) {
-        let mut msg = MSG::default();
+    /// Show or hide the cursor. `ShowCursor` keeps an internal display count,
+    /// so we only poke it on an actual transition to avoid drifting the count.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        if visible != self.cursor_visible {
+            self.cursor_visible = visible;
+            unsafe { ShowCursor(visible.into()); }
+        }
+    }
+
+    /// Confine the cursor to the client area, or release it. The clip is
+    /// re-applied from `window_proc` on resize/activation so it survives the
+    /// OS clearing it.
+    pub fn set_cursor_grab(&self, grab: bool) {
         unsafe {
-            while PeekMessageA(&mut msg, self.handle, 0, 0, PM_REMOVE) != BOOL(0) {
-                TranslateMessage(&mut msg);
-                DispatchMessageA(&mut msg);
+            if grab {
+                SetPropA(self.handle, s!("cursor_grab"), HANDLE(1));
+                clip_cursor_to_client(self.handle);
+            } else {
+                RemovePropA(self.handle, s!("cursor_grab"));
+                ClipCursor(None);
             }
         }
-        while let Ok(event) = self.event_receiver.try_recv() {
-            use WindowEvent::*;
-            match event {
-                MouseMoved {x,y} => {
-                    self.mouse.pos.x = x;
-                    self.mouse.pos.y = y;
-                    println!("mousemove: {x}, {y}");
-                },
-                WindowResized {width, height} => {
-                    self.size.height = height;
-                    self.size.width = width;
-                    println!("resized: {width}, {height}");
-                },
-                MouseButtonChanged(button, state) => {
-                    use MouseButton::*;
-                    use ButtonState::*;
-                    match button {
-                        Left if state == Up => self.mouse.left = false,
-                        Left if state == Down => self.mouse.left = true,
-                        Right if state == Up => self.mouse.right = false,
-                        Right if state == Down => self.mouse.right = true,
-                        _ => (),
-                    }
-                }
-            }
+    }
+
+    /// Set the cursor shape. The chosen cursor is stashed on a window prop so
+    /// `window_proc` can re-apply it when Windows sends `WM_SETCURSOR`.
+    pub fn set_cursor(&self, icon: CursorIcon) {
+        unsafe {
+            let cursor = LoadCursorW(None, icon.to_idc()).unwrap_or_default();
+            SetPropA(self.handle, s!("cursor_icon"), HANDLE(cursor.0));
+            SetCursor(cursor);
         }
     }
 
+    /// Bound the smallest client size the user may resize the window to, or
+    /// clear the limit with `None`. The limit is stashed on a window prop so
+    /// `window_proc` can answer `WM_GETMINMAXINFO` with it.
+    pub fn set_min_size(&self, size: Option<Rect2>) {
+        unsafe { set_size_limit(self.handle, s!("min_size"), size) };
+    }
+
+    /// Bound the largest client size the user may resize the window to, or
+    /// clear the limit with `None`. Stored and applied like [`Window::set_min_size`].
+    pub fn set_max_size(&self, size: Option<Rect2>) {
+        unsafe { set_size_limit(self.handle, s!("max_size"), size) };
+    }
+
     pub fn swap_buffers<T: Into<*const u8>>(&self, buffer: T) {
         let Rect2 { width, height } = self.size;
         unsafe {
@@ -149,17 +159,30 @@ impl Default for Mouse {
     }
 }
 
+/// The per-window endpoints owned by [`Platform`]: the pinned `Sender` whose
+/// stable address is handed to the window procedure via a window prop, and the
+/// `Receiver` that [`Platform::run`] drains to dispatch events.
+#[derive(Debug)]
+struct WindowChannel {
+    sender   : Pin<Box<Sender<WindowEvent>>>,
+    receiver : Receiver<WindowEvent>,
+}
+
 #[derive(Debug)]
 pub struct Platform {
     process_handle : HINSTANCE,
+    /// Live windows keyed by `HWND`, so each decoded message can be tagged with
+    /// its originating [`WindowId`].
+    windows        : HashMap<isize, WindowChannel>,
 }
 
 impl Platform {
     pub fn init() -> Self {
         let handle   = instance_handle();
         unsafe {
+            SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2);
             RegisterClassA(&WNDCLASSA {
-                style:         CS_HREDRAW | CS_HREDRAW | CS_OWNDC, 
+                style:         CS_HREDRAW | CS_HREDRAW | CS_OWNDC,
                 hInstance:     handle,
                 hCursor:       HCURSOR(0),
                 hIcon:         HICON(0),
@@ -170,11 +193,77 @@ impl Platform {
         };
         Self {
             process_handle : handle,
+            windows        : HashMap::new(),
+        }
+    }
+
+    pub fn create_window(&mut self) -> Window {
+        let window_name = s!("Rust GUI");
+        let (tx, rx)    = channel::<WindowEvent>();
+        let pinboxed_sender = Box::pin(tx);
+        let handle  = unsafe {
+            CreateWindowExA(
+                WINDOW_EX_STYLE(0),
+                CLASS_NAME,
+                window_name,
+                WS_OVERLAPPEDWINDOW | WS_VISIBLE,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                900_i32,
+                600_i32,
+                HWND(0),
+                HMENU(0),
+                self.process_handle,
+                Some((pinboxed_sender.as_ref().get_ref() as *const Sender<WindowEvent>).cast()),
+            )
+        };
+
+        unsafe { DragAcceptFiles(handle, TRUE) };
+
+        self.windows.insert(handle.0, WindowChannel {
+            sender   : pinboxed_sender,
+            receiver : rx,
+        });
+
+        Window {
+            id             : WindowId(handle.0),
+            handle,
+            cursor_visible : true,
+            scale_factor   : unsafe { GetDpiForWindow(handle) as f64 / 96.0 },
+            size           : Rect2::new(900, 600),
+            device_context : unsafe { GetDC(handle) },
+        }
+    }
+
+    /// Pump the thread's message queue once and dispatch every decoded event to
+    /// `handler`, tagged with the [`WindowId`] it originated from. Windows are
+    /// dropped from the registry once their [`WindowEvent::Destroyed`] is seen.
+    pub fn run(&mut self, mut handler: impl FnMut(WindowId, WindowEvent)) {
+        let mut msg = MSG::default();
+        unsafe {
+            while PeekMessageA(&mut msg, HWND(0), 0, 0, PM_REMOVE) != BOOL(0) {
+                TranslateMessage(&mut msg);
+                DispatchMessageA(&mut msg);
+            }
+        }
+
+        let mut destroyed = Vec::new();
+        for (&hwnd, channel) in &self.windows {
+            while let Ok(event) = channel.receiver.try_recv() {
+                if matches!(event, WindowEvent::Destroyed) {
+                    destroyed.push(hwnd);
+                }
+                handler(WindowId(hwnd), event);
+            }
+        }
+        for hwnd in destroyed {
+            self.windows.remove(&hwnd);
         }
     }
 
-    pub fn create_window(&self) -> Window {
-        Window::new(&self)
+    /// Whether every window has been destroyed; the caller's loop should exit.
+    pub fn should_exit(&self) -> bool {
+        self.windows.is_empty()
     }
 }
 
@@ -182,6 +271,66 @@ fn instance_handle() -> HINSTANCE {
     unsafe { GetModuleHandleA(None).unwrap() }
 }
 
+/// Clip the cursor to the window's client area in screen coordinates.
+unsafe fn clip_cursor_to_client(win_handle: HWND) {
+    let mut rect = RECT::default();
+    GetClientRect(win_handle, &mut rect);
+    let mut top_left     = POINT { x: rect.left,  y: rect.top };
+    let mut bottom_right = POINT { x: rect.right, y: rect.bottom };
+    ClientToScreen(win_handle, &mut top_left);
+    ClientToScreen(win_handle, &mut bottom_right);
+    ClipCursor(Some(&RECT {
+        left   : top_left.x,
+        top    : top_left.y,
+        right  : bottom_right.x,
+        bottom : bottom_right.y,
+    }));
+}
+
+/// Pack an optional client-size limit into a window prop, or clear it. The
+/// width and height are folded into the prop's `isize` so `window_proc` can
+/// read them back without touching the registry; `None` removes the prop.
+unsafe fn set_size_limit(win_handle: HWND, name: PCSTR, size: Option<Rect2>) {
+    match size {
+        Some(Rect2 { width, height }) => {
+            let packed = ((width as isize) << 32) | height as isize;
+            SetPropA(win_handle, name, HANDLE(packed));
+        }
+        None => {
+            RemovePropA(win_handle, name);
+        }
+    }
+}
+
+/// Read back a client-size limit stashed by [`set_size_limit`], returning
+/// `None` when the prop is absent.
+unsafe fn read_size_limit(win_handle: HWND, name: PCSTR) -> Option<Rect2> {
+    let prop = GetPropA(win_handle, name);
+    if prop.0 == 0 {
+        None
+    } else {
+        Some(Rect2::new((prop.0 >> 32) as u32, (prop.0 & 0xffff_ffff) as u32))
+    }
+}
+
+/// Convert a client-area size into the full window size Windows expects in a
+/// `MINMAXINFO` track field, accounting for the non-client frame.
+unsafe fn client_size_to_track(win_handle: HWND, size: Rect2) -> POINT {
+    let style    = WINDOW_STYLE(GetWindowLongA(win_handle, GWL_STYLE) as u32);
+    let ex_style = WINDOW_EX_STYLE(GetWindowLongA(win_handle, GWL_EXSTYLE) as u32);
+    let mut rect = RECT {
+        left   : 0,
+        top    : 0,
+        right  : size.width  as i32,
+        bottom : size.height as i32,
+    };
+    AdjustWindowRectEx(&mut rect, style, FALSE, ex_style);
+    POINT {
+        x: rect.right - rect.left,
+        y: rect.bottom - rect.top,
+    }
+}
+
 unsafe extern "system" fn window_proc(
     win_handle: HWND,
     message:    u32,
@@ -241,7 +390,46 @@ unsafe extern "system" fn window_proc(
                     println!("{e}");
                     panic!()
                 }
-            }   
+            }
+            // Re-apply an active cursor clip, which the OS drops on resize.
+            if GetPropA(win_handle, s!("cursor_grab")).0 != 0 {
+                clip_cursor_to_client(win_handle);
+            }
+        }
+        WM_ACTIVATE => {
+            // Likewise re-apply the clip when the window regains focus. The low
+            // word of wparam is WA_INACTIVE (0) when the window is deactivated.
+            if (wparam.0 & 0xffff) != 0 && GetPropA(win_handle, s!("cursor_grab")).0 != 0 {
+                clip_cursor_to_client(win_handle);
+            }
+        }
+        WM_GETMINMAXINFO => {
+            // lparam points at a MINMAXINFO whose track-size fields bound the
+            // live resize. Overwrite them with the configured client limits,
+            // grown to window size for the non-client frame.
+            let info = lparam.0 as *mut MINMAXINFO;
+            if let Some(info) = info.as_mut() {
+                if let Some(min) = read_size_limit(win_handle, s!("min_size")) {
+                    info.ptMinTrackSize = client_size_to_track(win_handle, min);
+                }
+                if let Some(max) = read_size_limit(win_handle, s!("max_size")) {
+                    info.ptMaxTrackSize = client_size_to_track(win_handle, max);
+                }
+            }
+            // Handled: returning LRESULT(0) lets the OS enforce the bounds.
+        }
+        WM_SETCURSOR => {
+            // The hit-test result is in the low word of lparam; only claim the
+            // cursor over the client area so the OS keeps its resize arrows on
+            // the non-client frame.
+            let hit_test = (lparam.0 & 0xffff) as u32;
+            let cursor = GetPropA(win_handle, s!("cursor_icon"));
+            if cursor.0 != 0 && hit_test == HTCLIENT {
+                SetCursor(HCURSOR(cursor.0));
+                result = LRESULT(1);
+            } else {
+                result = DefWindowProcA(win_handle, message, wparam, lparam);
+            }
         }
         WM_LBUTTONDOWN => {
             if let Some(channel) = get_event_channel() {
@@ -287,13 +475,201 @@ unsafe extern "system" fn window_proc(
                 }
             }
         }
+        WM_KEYDOWN | WM_SYSKEYDOWN | WM_KEYUP | WM_SYSKEYUP => {
+            if let Some(channel) = get_event_channel() {
+                let state = if message == WM_KEYDOWN || message == WM_SYSKEYDOWN {
+                    Down
+                } else {
+                    Up
+                };
+                // scancode in bits 16-23, extended-key flag in bit 24 of lparam
+                let scancode = ((lparam.0 >> 16) & 0xff) as u32;
+                let extended = (lparam.0 >> 24) & 0x1 != 0;
+                let vk = VIRTUAL_KEY(wparam.0 as u16);
+                if let Some(key) = translate_key(vk, scancode, extended) {
+                    if let Err(e) = channel.send(KeyboardInput { key, state, scancode }) {
+                        println!("{e}");
+                        panic!();
+                    }
+                }
+                // Only surface a modifier change when the set actually differs
+                // from the last one reported, so auto-repeat and ordinary keys
+                // don't flood the channel. The last set is cached on a window
+                // prop, the same way the cursor state is stashed for reuse.
+                let modifiers = current_modifiers();
+                let packed = pack_modifiers(modifiers);
+                if GetPropA(win_handle, s!("modifiers")).0 != packed {
+                    SetPropA(win_handle, s!("modifiers"), HANDLE(packed));
+                    if let Err(e) = channel.send(ModifiersChanged(modifiers)) {
+                        println!("{e}");
+                        panic!();
+                    }
+                }
+            }
+            result = DefWindowProcA(win_handle, message, wparam, lparam);
+        }
+        WM_DPICHANGED => {
+            // New DPI in the low word of wparam; lparam points at the size and
+            // position the OS suggests for the new scale factor.
+            let dpi = (wparam.0 & 0xffff) as u16;
+            let scale_factor = dpi as f64 / 96.0;
+            let suggested = lparam.0 as *const RECT;
+            if let Some(rect) = suggested.as_ref() {
+                SetWindowPos(
+                    win_handle,
+                    HWND(0),
+                    rect.left,
+                    rect.top,
+                    rect.right - rect.left,
+                    rect.bottom - rect.top,
+                    SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+                if let Some(channel) = get_event_channel() {
+                    // Report the client area, not the suggested outer rect, to
+                    // match WindowResized so callers size framebuffers
+                    // consistently.
+                    let mut client = RECT::default();
+                    GetClientRect(win_handle, &mut client);
+                    let new_size = Rect2::new(
+                        client.right  as u32,
+                        client.bottom as u32,
+                    );
+                    if let Err(e) = channel.send(ScaleFactorChanged { scale_factor, new_size }) {
+                        println!("{e}");
+                        panic!();
+                    }
+                }
+            }
+        }
+        WM_INPUT => {
+            if let Some(channel) = get_event_channel() {
+                let hrawinput = HRAWINPUT(lparam.0);
+                let header_size = std::mem::size_of::<RAWINPUTHEADER>() as u32;
+                // First call with a null buffer to learn the required size.
+                let mut size = 0u32;
+                GetRawInputData(hrawinput, RID_INPUT, None, &mut size, header_size);
+                let mut raw = RAWINPUT::default();
+                if size as usize <= std::mem::size_of::<RAWINPUT>() {
+                    let read = GetRawInputData(
+                        hrawinput,
+                        RID_INPUT,
+                        Some(&mut raw as *mut _ as *mut std::ffi::c_void),
+                        &mut size,
+                        header_size,
+                    );
+                    if read != u32::MAX && raw.header.dwType == RIM_TYPEMOUSE.0 {
+                        let mouse = raw.data.mouse;
+                        let flags = mouse.usFlags;
+                        // Ignore absolute-positioning devices (e.g. tablets); we
+                        // only want relative motion here.
+                        if flags & MOUSE_MOVE_ABSOLUTE as u16 == 0 {
+                            let dx = mouse.lLastX;
+                            let dy = mouse.lLastY;
+                            if (dx, dy) != (0, 0) {
+                                if let Err(e) = channel.send(RawMouseMotion { dx, dy }) {
+                                    println!("{e}");
+                                    panic!();
+                                }
+                            }
+                        }
+                        // usButtonData carries the signed wheel delta when the
+                        // RI_MOUSE_WHEEL flag is set.
+                        let buttons = mouse.Anonymous.Anonymous;
+                        if buttons.usButtonFlags as u32 & RI_MOUSE_WHEEL != 0 {
+                            let delta = buttons.usButtonData as i16;
+                            if let Err(e) = channel.send(RawMouseWheel { delta }) {
+                                println!("{e}");
+                                panic!();
+                            }
+                        }
+                    }
+                }
+            }
+            result = DefWindowProcA(win_handle, message, wparam, lparam);
+        }
+        WM_CHAR => {
+            if let Some(channel) = get_event_channel() {
+                let unit = wparam.0 as u16;
+                // WM_CHAR delivers one UTF-16 code unit at a time; a non-BMP
+                // character arrives as a high surrogate followed by a low one,
+                // so stash the high half on a prop until its partner shows up.
+                let code_point = match unit {
+                    0xD800..=0xDBFF => {
+                        SetPropA(win_handle, s!("pending_surrogate"), HANDLE(unit as isize));
+                        None
+                    }
+                    0xDC00..=0xDFFF => {
+                        let high = GetPropA(win_handle, s!("pending_surrogate")).0 as u16;
+                        RemovePropA(win_handle, s!("pending_surrogate"));
+                        if (0xD800..=0xDBFF).contains(&high) {
+                            Some(0x10000
+                                + (((high - 0xD800) as u32) << 10)
+                                + (unit - 0xDC00) as u32)
+                        } else {
+                            None
+                        }
+                    }
+                    _ => Some(unit as u32),
+                };
+                if let Some(c) = code_point.and_then(char::from_u32) {
+                    if let Err(e) = channel.send(ReceivedCharacter(c)) {
+                        println!("{e}");
+                        panic!();
+                    }
+                }
+            }
+        }
+        WM_DROPFILES => {
+            if let Some(channel) = get_event_channel() {
+                let hdrop = HDROP(wparam.0);
+                // Passing 0xFFFFFFFF as the index asks for the file count.
+                let count = DragQueryFileW(hdrop, 0xFFFFFFFF, None);
+                let mut paths = Vec::with_capacity(count as usize);
+                for index in 0..count {
+                    let len = DragQueryFileW(hdrop, index, None) as usize;
+                    let mut buffer = vec![0u16; len + 1];
+                    let written = DragQueryFileW(hdrop, index, Some(&mut buffer)) as usize;
+                    buffer.truncate(written);
+                    paths.push(PathBuf::from(OsString::from_wide(&buffer)));
+                }
+                let mut point = POINT::default();
+                DragQueryPoint(hdrop, &mut point);
+                DragFinish(hdrop);
+                if let Err(e) = channel.send(FilesDropped {
+                    paths,
+                    x: point.x as u32,
+                    y: point.y as u32,
+                }) {
+                    println!("{e}");
+                    panic!();
+                }
+            }
+        }
+        WM_CLOSE => {
+            if let Some(channel) = get_event_channel() {
+                if let Err(e) = channel.send(CloseRequested) {
+                    println!("{e}");
+                    panic!();
+                }
+            }
+            result = DefWindowProcA(win_handle, message, wparam, lparam);
+        }
+        WM_DESTROY => {
+            if let Some(channel) = get_event_channel() {
+                if let Err(e) = channel.send(Destroyed) {
+                    println!("{e}");
+                    panic!();
+                }
+            }
+            RemovePropA(win_handle, s!("event_channel"));
+        }
         _ => result = DefWindowProcA(win_handle, message, wparam, lparam),
     }
     result
 }
 
 #[derive(Debug)]
-enum WindowEvent {
+pub enum WindowEvent {
     MouseMoved {
         x: u32,
         y: u32
@@ -302,11 +678,161 @@ enum WindowEvent {
     WindowResized {
         width: u32,
         height: u32,
+    },
+    KeyboardInput {
+        key: Key,
+        state: ButtonState,
+        scancode: u32,
+    },
+    ModifiersChanged(Modifiers),
+    ReceivedCharacter(char),
+    RawMouseMotion {
+        dx: i32,
+        dy: i32,
+    },
+    RawMouseWheel {
+        delta: i16,
+    },
+    ScaleFactorChanged {
+        scale_factor: f64,
+        new_size: Rect2,
+    },
+    FilesDropped {
+        paths: Vec<PathBuf>,
+        x: u32,
+        y: u32,
+    },
+    CloseRequested,
+    Destroyed,
+}
+
+/// Active state of the keyboard modifier keys.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Modifiers {
+    pub shift : bool,
+    pub ctrl  : bool,
+    pub alt   : bool,
+    pub logo  : bool,
+}
+
+/// A portable physical key, translated from a Win32 virtual-key code.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Key {
+    A, B, C, D, E, F, G, H, I, J, K, L, M,
+    N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Key0, Key1, Key2, Key3, Key4,
+    Key5, Key6, Key7, Key8, Key9,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+    F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24,
+    Up, Down, Left, Right,
+    LShift, RShift,
+    LControl, RControl,
+    LAlt, RAlt,
+    LLogo, RLogo,
+    Enter, Escape, Space, Tab, Backspace,
+}
+
+/// Fold the modifier flags into a nonzero `isize` so the last-reported set can
+/// be cached on a window prop and compared cheaply. The `0x10` marker keeps the
+/// value nonzero, distinguishing "no modifiers" from an absent prop.
+fn pack_modifiers(m: Modifiers) -> isize {
+    (m.shift as isize)
+        | (m.ctrl as isize) << 1
+        | (m.alt  as isize) << 2
+        | (m.logo as isize) << 3
+        | 0x10
+}
+
+/// Query the live modifier-key state.
+unsafe fn current_modifiers() -> Modifiers {
+    let down = |vk: VIRTUAL_KEY| (GetKeyState(vk.0 as i32) as u16 & 0x8000) != 0;
+    Modifiers {
+        shift : down(VK_SHIFT),
+        ctrl  : down(VK_CONTROL),
+        alt   : down(VK_MENU),
+        logo  : down(VK_LWIN) || down(VK_RWIN),
+    }
+}
+
+/// Translate a virtual-key code into a portable [`Key`], disambiguating the
+/// left/right modifier pairs using the scancode and extended-key flag.
+unsafe fn translate_key(vk: VIRTUAL_KEY, scancode: u32, extended: bool) -> Option<Key> {
+    use Key::*;
+    Some(match vk {
+        // VK_SHIFT does not distinguish sides: map the scancode back to the
+        // sided virtual key.
+        VK_SHIFT => {
+            match VIRTUAL_KEY(MapVirtualKeyW(scancode, MAPVK_VSC_TO_VK_EX) as u16) {
+                VK_RSHIFT => RShift,
+                _ => LShift,
+            }
+        }
+        // Ctrl/Alt: the right-hand key sets the extended-key bit.
+        VK_CONTROL => if extended { RControl } else { LControl },
+        VK_MENU    => if extended { RAlt } else { LAlt },
+        VK_LSHIFT   => LShift,
+        VK_RSHIFT   => RShift,
+        VK_LCONTROL => LControl,
+        VK_RCONTROL => RControl,
+        VK_LMENU    => LAlt,
+        VK_RMENU    => RAlt,
+        VK_LWIN     => LLogo,
+        VK_RWIN     => RLogo,
+        VK_UP     => Up,
+        VK_DOWN   => Down,
+        VK_LEFT   => Left,
+        VK_RIGHT  => Right,
+        VK_RETURN => Enter,
+        VK_ESCAPE => Escape,
+        VK_SPACE  => Space,
+        VK_TAB    => Tab,
+        VK_BACK   => Backspace,
+        VK_A => A, VK_B => B, VK_C => C, VK_D => D, VK_E => E, VK_F => F,
+        VK_G => G, VK_H => H, VK_I => I, VK_J => J, VK_K => K, VK_L => L,
+        VK_M => M, VK_N => N, VK_O => O, VK_P => P, VK_Q => Q, VK_R => R,
+        VK_S => S, VK_T => T, VK_U => U, VK_V => V, VK_W => W, VK_X => X,
+        VK_Y => Y, VK_Z => Z,
+        VK_0 => Key0, VK_1 => Key1, VK_2 => Key2, VK_3 => Key3, VK_4 => Key4,
+        VK_5 => Key5, VK_6 => Key6, VK_7 => Key7, VK_8 => Key8, VK_9 => Key9,
+        VK_F1 => F1, VK_F2 => F2, VK_F3 => F3, VK_F4 => F4, VK_F5 => F5,
+        VK_F6 => F6, VK_F7 => F7, VK_F8 => F8, VK_F9 => F9, VK_F10 => F10,
+        VK_F11 => F11, VK_F12 => F12, VK_F13 => F13, VK_F14 => F14,
+        VK_F15 => F15, VK_F16 => F16, VK_F17 => F17, VK_F18 => F18,
+        VK_F19 => F19, VK_F20 => F20, VK_F21 => F21, VK_F22 => F22,
+        VK_F23 => F23, VK_F24 => F24,
+        _ => return None,
+    })
+}
+
+/// A standard system cursor shape.
+#[derive(Debug, Copy, Clone)]
+pub enum CursorIcon {
+    Arrow,
+    Hand,
+    IBeam,
+    Crosshair,
+    ResizeNS,
+    ResizeEW,
+    Wait,
+}
+
+impl CursorIcon {
+    fn to_idc(self) -> PCWSTR {
+        use CursorIcon::*;
+        match self {
+            Arrow     => IDC_ARROW,
+            Hand      => IDC_HAND,
+            IBeam     => IDC_IBEAM,
+            Crosshair => IDC_CROSS,
+            ResizeNS  => IDC_SIZENS,
+            ResizeEW  => IDC_SIZEWE,
+            Wait      => IDC_WAIT,
+        }
     }
 }
 
 #[derive(Debug)]
-enum MouseButton {
+pub enum MouseButton {
     Left,
     Right,
     Middle,
@@ -315,7 +841,7 @@ enum MouseButton {
 }
 
 #[derive(Debug, PartialEq)]
-enum ButtonState {
+pub enum ButtonState {
     Up,
     Down
 }
\ No newline at end of file